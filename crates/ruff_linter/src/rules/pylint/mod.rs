@@ -0,0 +1,41 @@
+//! Rules from [pylint](https://pypi.org/project/pylint/).
+pub(crate) mod rules;
+pub(crate) mod settings;
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use anyhow::Result;
+    use test_case::test_case;
+
+    use crate::registry::Rule;
+    use crate::test::test_path;
+    use crate::{assert_messages, settings};
+
+    #[test_case(Rule::DictIndexMissingItems, Path::new("dict_index_missing_items.py"))]
+    #[test_case(Rule::DictIterMissingItems, Path::new("dict_iter_missing_items.py"))]
+    fn rules(rule_code: Rule, path: &Path) -> Result<()> {
+        let snapshot = format!("{}_{}", rule_code.noqa_code(), path.to_string_lossy());
+        let diagnostics = test_path(
+            Path::new("pylint").join(path).as_path(),
+            &settings::LinterSettings::for_rule(rule_code),
+        )?;
+        assert_messages!(snapshot, diagnostics);
+    }
+
+    #[test]
+    fn dict_iter_missing_items_conservative() -> Result<()> {
+        let diagnostics = test_path(
+            Path::new("pylint").join("dict_iter_missing_items.py").as_path(),
+            &settings::LinterSettings {
+                pylint: super::settings::Settings {
+                    dict_iter_missing_items_strictness:
+                        super::settings::DictIterMissingItemsStrictness::Conservative,
+                },
+                ..settings::LinterSettings::for_rule(Rule::DictIterMissingItems)
+            },
+        )?;
+        assert_messages!("dict_iter_missing_items_conservative", diagnostics);
+    }
+}
@@ -0,0 +1,250 @@
+use ruff_python_ast::visitor::{walk_expr, walk_stmt, Visitor};
+use ruff_python_ast::{Expr, ExprAttribute, ExprCall, ExprSubscript, Stmt};
+
+use ruff_diagnostics::{AlwaysFixableViolation, Diagnostic, Edit, Fix};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_text_size::{Ranged, TextRange};
+
+use crate::checkers::ast::Checker;
+use crate::rules::pylint::rules::dict_iter_missing_items::resolve_dict_binding;
+
+use ruff_python_semantic::analyze::typing::is_dict;
+
+/// ## What it does
+/// Checks for a `for` loop over a dictionary's keys that then indexes back
+/// into the same dictionary with the loop variable.
+///
+/// ## Why is this bad?
+/// Iterating over `.items()` avoids the repeated dictionary lookups and
+/// communicates the intent (key *and* value) directly.
+///
+/// ## Example
+/// ```python
+/// data = {"Paris": 2_165_423, "New York City": 8_804_190, "Tokyo": 13_988_129}
+/// for city in data:
+///     print(f"{city} has population {data[city]}.")
+/// ```
+///
+/// Use instead:
+/// ```python
+/// data = {"Paris": 2_165_423, "New York City": 8_804_190, "Tokyo": 13_988_129}
+/// for city, population in data.items():
+///     print(f"{city} has population {population}.")
+/// ```
+#[violation]
+pub struct DictIndexMissingItems;
+
+impl AlwaysFixableViolation for DictIndexMissingItems {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        format!("Use `.items()` to iterate over key-value pairs instead of indexing into the dictionary")
+    }
+
+    fn fix_title(&self) -> String {
+        format!("Replace with `.items()`")
+    }
+}
+
+pub(crate) fn dict_index_missing_items(checker: &mut Checker, target: &Expr, iter: &Expr, body: &[Stmt]) {
+    let Some(loop_var) = target.as_name_expr() else {
+        return;
+    };
+
+    let Some(dict_name) = iter.as_name_expr() else {
+        return;
+    };
+
+    let Some(binding) = resolve_dict_binding(checker.semantic(), iter) else {
+        return;
+    };
+
+    if !is_dict(binding, checker.semantic()) {
+        return;
+    }
+
+    let mut collector = SubscriptCollector {
+        dict_name: &dict_name.id,
+        loop_var: &loop_var.id,
+        matches: Vec::new(),
+        bail: false,
+    };
+    for stmt in body {
+        collector.visit_stmt(stmt);
+    }
+
+    if collector.bail || collector.matches.is_empty() {
+        return;
+    }
+
+    let Some(value_name) = select_value_binding_name(checker, &loop_var.id) else {
+        return;
+    };
+
+    let mut diagnostic = Diagnostic::new(DictIndexMissingItems, target.range());
+
+    let mut edits = vec![
+        Edit::range_replacement(format!("{}, {value_name}", loop_var.id), target.range()),
+        Edit::range_replacement(format!("{}.items()", dict_name.id), iter.range()),
+    ];
+    edits.extend(
+        collector
+            .matches
+            .iter()
+            .map(|range| Edit::range_replacement(value_name.to_string(), *range)),
+    );
+
+    diagnostic.set_fix(Fix::safe_edits(edits.remove(0), edits));
+    checker.diagnostics.push(diagnostic);
+}
+
+/// Pick a name for the new binding the fix introduces to hold the dict's
+/// value, bailing out (returning `None`) if the obvious choice, `value`, is
+/// already bound somewhere visible from the loop. Reusing a name that's
+/// already in use would silently repurpose it rather than just adding a new
+/// binding -- e.g. `for value in data: ... data[value] ...` must not become
+/// `for value, value in data.items(): ...`.
+fn select_value_binding_name(checker: &Checker, loop_var: &str) -> Option<&'static str> {
+    const CANDIDATE: &str = "value";
+    if loop_var == CANDIDATE {
+        return None;
+    }
+    if checker.semantic().lookup_symbol(CANDIDATE).is_some() {
+        return None;
+    }
+    Some(CANDIDATE)
+}
+
+/// Walks a loop body collecting `dict_name[loop_var]` subscript reads, while
+/// bailing out if the loop variable is rebound or the dict is mutated, since
+/// either would make the rewrite unsafe.
+struct SubscriptCollector<'a> {
+    dict_name: &'a str,
+    loop_var: &'a str,
+    matches: Vec<TextRange>,
+    bail: bool,
+}
+
+impl<'a> Visitor<'a> for SubscriptCollector<'a> {
+    fn visit_stmt(&mut self, stmt: &'a Stmt) {
+        match stmt {
+            Stmt::Assign(assign) => {
+                for target in &assign.targets {
+                    self.check_rebind(target);
+                    // `data[loop_var] = ...` is a write, not a read: bail
+                    // before `walk_stmt` below visits the target expression,
+                    // so it's never mistaken for a `data[loop_var]` read.
+                    if self.is_dict_subscript(target) {
+                        self.bail = true;
+                    }
+                }
+            }
+            Stmt::AugAssign(aug_assign) => {
+                self.check_rebind(&aug_assign.target);
+                if self.is_dict_subscript(&aug_assign.target) {
+                    self.bail = true;
+                }
+            }
+            Stmt::For(for_stmt) => self.check_rebind(&for_stmt.target),
+            Stmt::With(with_stmt) => {
+                for item in &with_stmt.items {
+                    if let Some(vars) = &item.optional_vars {
+                        self.check_rebind(vars);
+                    }
+                }
+            }
+            Stmt::Try(try_stmt) => {
+                for handler in &try_stmt.handlers {
+                    let ruff_python_ast::ExceptHandler::ExceptHandler(handler) = handler;
+                    if handler
+                        .name
+                        .as_ref()
+                        .is_some_and(|name| name.as_str() == self.loop_var)
+                    {
+                        self.bail = true;
+                    }
+                }
+            }
+            Stmt::Delete(delete) => {
+                for target in &delete.targets {
+                    if self.is_dict_subscript(target) {
+                        self.bail = true;
+                    }
+                }
+            }
+            _ => {}
+        }
+        walk_stmt(self, stmt);
+    }
+
+    fn visit_expr(&mut self, expr: &'a Expr) {
+        if self.bail {
+            return;
+        }
+
+        if let Expr::Named(named) = expr {
+            self.check_rebind(&named.target);
+        }
+
+        if let Expr::Subscript(ExprSubscript { value, slice, range, .. }) = expr {
+            if value.as_name_expr().is_some_and(|name| name.id == self.dict_name) {
+                if slice.as_name_expr().is_some_and(|name| name.id == self.loop_var) {
+                    self.matches.push(*range);
+                } else {
+                    // Some other key is used to index into the dict; we can't
+                    // assume every read goes through `loop_var`.
+                    self.bail = true;
+                }
+            }
+        }
+
+        if let Expr::Call(ExprCall { func, .. }) = expr {
+            if let Expr::Attribute(ExprAttribute { value, attr, .. }) = func.as_ref() {
+                if value.as_name_expr().is_some_and(|name| name.id == self.dict_name)
+                    && matches!(
+                        attr.as_str(),
+                        "update" | "pop" | "popitem" | "clear" | "setdefault"
+                    )
+                {
+                    self.bail = true;
+                }
+            }
+        }
+
+        walk_expr(self, expr);
+    }
+}
+
+impl<'a> SubscriptCollector<'a> {
+    /// Bail if `target` binds `loop_var`, recursing into tuple/list unpacking
+    /// and starred targets so `key, extra = ...` and similar are caught, not
+    /// just a bare top-level name.
+    fn check_rebind(&mut self, target: &Expr) {
+        match target {
+            Expr::Name(name) => {
+                if name.id == self.loop_var {
+                    self.bail = true;
+                }
+            }
+            Expr::Tuple(tuple) => {
+                for elt in &tuple.elts {
+                    self.check_rebind(elt);
+                }
+            }
+            Expr::List(list) => {
+                for elt in &list.elts {
+                    self.check_rebind(elt);
+                }
+            }
+            Expr::Starred(starred) => self.check_rebind(&starred.value),
+            _ => {}
+        }
+    }
+
+    fn is_dict_subscript(&self, expr: &Expr) -> bool {
+        matches!(
+            expr,
+            Expr::Subscript(ExprSubscript { value, .. })
+                if value.as_name_expr().is_some_and(|name| name.id == self.dict_name)
+        )
+    }
+}
@@ -1,11 +1,13 @@
-use ruff_python_ast::{Expr, ExprTuple};
+use ruff_python_ast::{Expr, ExprAttribute, ExprCall, ExprDictComp, ExprSubscript, ExprTuple};
 
 use ruff_diagnostics::{AlwaysFixableViolation, Diagnostic, Edit, Fix};
 use ruff_macros::{derive_message_formats, violation};
 use ruff_python_semantic::analyze::typing::is_dict;
+use ruff_python_semantic::{Binding, SemanticModel};
 use ruff_text_size::Ranged;
 
 use crate::checkers::ast::Checker;
+use crate::rules::pylint::settings::DictIterMissingItemsStrictness;
 
 /// ## What it does
 /// Checks for unpacking a dictionary in a for loop without calling `.items()`.
@@ -14,6 +16,12 @@ use crate::checkers::ast::Checker;
 /// You are likely looking for an iteration over key, value pairs which can only be achieved
 /// when calling `.items()`.
 ///
+/// By default, the rule assumes keys are scalar and flags any dict whose key
+/// type can't be proven to be 2-tuples; set
+/// `lint.pylint.dict-iter-missing-items-strictness = "conservative"` to flip
+/// this and only flag dicts whose keys are known *not* to be 2-tuples, for
+/// codebases that commonly use tuple-keyed dicts.
+///
 /// ## Example
 /// ```python
 /// data = {"Paris": 2_165_423, "New York City": 8_804_190, "Tokyo": 13_988_129}
@@ -50,43 +58,203 @@ pub(crate) fn dict_iter_missing_items(checker: &mut Checker, target: &Expr, iter
         return;
     };
 
-    let Some(name) = iter.as_name_expr() else {
+    let Some(binding) = resolve_dict_binding(checker.semantic(), iter) else {
         return;
     };
 
-    let Some(binding) = checker
-        .semantic()
-        .only_binding(name)
-        .map(|id| checker.semantic().binding(id))
-    else {
-        return;
-    };
-    if !is_dict(binding, checker.semantic()) {
+    // `is_dict` doesn't treat the result of `dict.fromkeys(...)` as a dict.
+    let is_dict_fromkeys = binding
+        .statement(checker.semantic())
+        .and_then(|statement| statement.as_assign_stmt())
+        .and_then(|assignment| assignment.value.as_call_expr())
+        .and_then(|call| call.func.as_attribute_expr())
+        .is_some_and(|func| {
+            func.attr.as_str() == "fromkeys"
+                && func.value.as_name_expr().is_some_and(|name| name.id == "dict")
+        });
+    if !is_dict(binding, checker.semantic()) && !is_dict_fromkeys {
         return;
     }
 
-    // If we can reliably determine that a dictionary has keys that are tuples of two we don't warn
-    if let Some(statement) = binding.statement(checker.semantic()) {
-        if let Some(assignment) = statement.as_assign_stmt() {
-            if let Some(dict_expr) = assignment.value.as_dict_expr() {
-                if dict_expr.keys.iter().all(|elt| {
-                    elt.as_ref().is_some_and(|x| {
-                        if let Some(tuple) = x.as_tuple_expr() {
-                            return tuple.elts.len() == 2;
-                        }
-                        false
-                    })
-                }) {
-                    return;
-                }
-            }
-        }
+    let key_shape = classify_key_shape(binding, checker.semantic());
+    let suppress = match checker.settings.pylint.dict_iter_missing_items_strictness {
+        // Assume scalar keys unless we can prove otherwise: only suppress
+        // once the keys are *known* to be 2-tuples.
+        DictIterMissingItemsStrictness::AssumeScalarKeys => key_shape == KeyShape::AllTwoTuples,
+        // Conservative: only flag once the keys are *known not* to be
+        // 2-tuples; an ambiguous key type is suppressed rather than risk a
+        // false positive.
+        DictIterMissingItemsStrictness::Conservative => key_shape != KeyShape::NotTwoTuples,
     };
+    if suppress {
+        return;
+    }
 
     let mut diagnostic = Diagnostic::new(DictIterMissingItems, iter.range());
     diagnostic.set_fix(Fix::safe_edit(Edit::range_replacement(
-        format!("{}.items()", name.id),
+        format!("{}.items()", checker.locator().slice(iter.range())),
         iter.range(),
     )));
     checker.diagnostics.push(diagnostic);
 }
+
+/// Resolve `iter` to the [`Binding`] that introduced its value.
+///
+/// A bare [`Expr::Name`] resolves to its only binding, which covers local
+/// variables and parameters (e.g. one annotated `dict[...]`). An
+/// [`Expr::Attribute`] is resolved via [`SemanticModel::lookup_attribute`],
+/// which follows class-qualified names such as `Class.attr`; instance
+/// attributes such as `self.attr` are not resolved, so loops over them are
+/// not recognized.
+pub(super) fn resolve_dict_binding<'a>(
+    semantic: &'a SemanticModel,
+    iter: &Expr,
+) -> Option<&'a Binding<'a>> {
+    match iter {
+        Expr::Name(name) => semantic.only_binding(name).map(|id| semantic.binding(id)),
+        Expr::Attribute(_) => semantic
+            .lookup_attribute(iter)
+            .map(|id| semantic.binding(id)),
+        _ => None,
+    }
+}
+
+/// What we can tell, statically, about the shape of a dict binding's keys.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum KeyShape {
+    /// Every key is provably a 2-tuple.
+    AllTwoTuples,
+    /// No key is a 2-tuple.
+    NotTwoTuples,
+    /// Could not be determined, e.g. the key type isn't statically known.
+    Unknown,
+}
+
+/// Classify the keys of `binding`'s dict: from an inline dict literal or dict
+/// comprehension whose keys are all (or none) 2-tuples, from `dict.fromkeys(...)`
+/// or `dict(zip(...))` called on a literal iterable of (non-)2-tuples, or from
+/// an annotated `dict[tuple[_, _], _]` (or `Dict[...]`) type.
+fn classify_key_shape(binding: &Binding, semantic: &SemanticModel) -> KeyShape {
+    let Some(statement) = binding.statement(semantic) else {
+        return KeyShape::Unknown;
+    };
+
+    if let Some(assignment) = statement.as_assign_stmt() {
+        match assignment.value.as_ref() {
+            Expr::Dict(dict_expr) => {
+                let is_two_tuple = |elt: &Option<Expr>| {
+                    elt.as_ref()
+                        .is_some_and(|x| x.as_tuple_expr().is_some_and(|tuple| tuple.elts.len() == 2))
+                };
+                if dict_expr.keys.iter().all(is_two_tuple) {
+                    return KeyShape::AllTwoTuples;
+                }
+                if dict_expr.keys.iter().all(|elt| !is_two_tuple(elt)) {
+                    return KeyShape::NotTwoTuples;
+                }
+                return KeyShape::Unknown;
+            }
+            Expr::DictComp(dict_comp) => return classify_dict_comp_key_shape(dict_comp),
+            Expr::Call(call) => return classify_call_key_shape(call),
+            _ => {}
+        }
+    }
+
+    if let Some(ann_assign) = statement.as_ann_assign_stmt() {
+        return classify_annotation_key_shape(&ann_assign.annotation);
+    }
+
+    if let Some(function_def) = statement.as_function_def_stmt() {
+        return function_def
+            .parameters
+            .iter()
+            .find(|parameter| parameter.name().as_str() == binding.name(semantic.source()))
+            .and_then(|parameter| parameter.annotation())
+            .map_or(KeyShape::Unknown, classify_annotation_key_shape);
+    }
+
+    KeyShape::Unknown
+}
+
+/// Classify the key shape of a dict comprehension from its key expression.
+fn classify_dict_comp_key_shape(dict_comp: &ExprDictComp) -> KeyShape {
+    match dict_comp.key.as_ref() {
+        Expr::Tuple(tuple) if tuple.elts.len() == 2 => KeyShape::AllTwoTuples,
+        Expr::Tuple(_) => KeyShape::NotTwoTuples,
+        Expr::Name(_) | Expr::Attribute(_) | Expr::NumberLiteral(_) | Expr::StringLiteral(_) => {
+            KeyShape::NotTwoTuples
+        }
+        _ => KeyShape::Unknown,
+    }
+}
+
+/// Classify the key shape of a dict built via `dict.fromkeys(iterable)` or
+/// `dict(zip(iterable, ...))`, by inspecting a literal `iterable` of keys
+/// (or key/value pairs, for `zip`).
+fn classify_call_key_shape(call: &ExprCall) -> KeyShape {
+    if let Expr::Attribute(ExprAttribute { value, attr, .. }) = call.func.as_ref() {
+        if value.as_name_expr().is_some_and(|name| name.id == "dict") && attr.as_str() == "fromkeys"
+        {
+            if let Some(iterable) = call.arguments.args.first() {
+                return classify_iterable_key_shape(iterable);
+            }
+        }
+    }
+
+    if call.func.as_name_expr().is_some_and(|name| name.id == "dict") {
+        if let Some(Expr::Call(zip_call)) = call.arguments.args.first() {
+            if zip_call.func.as_name_expr().is_some_and(|name| name.id == "zip") {
+                if let Some(keys) = zip_call.arguments.args.first() {
+                    return classify_iterable_key_shape(keys);
+                }
+            }
+        }
+    }
+
+    KeyShape::Unknown
+}
+
+/// Classify the key shape of a literal `list`/`set`/`tuple` of keys.
+fn classify_iterable_key_shape(iterable: &Expr) -> KeyShape {
+    let elts = match iterable {
+        Expr::List(list) => &list.elts,
+        Expr::Set(set) => &set.elts,
+        Expr::Tuple(tuple) => &tuple.elts,
+        _ => return KeyShape::Unknown,
+    };
+    if elts.is_empty() {
+        return KeyShape::Unknown;
+    }
+    let is_two_tuple = |elt: &Expr| elt.as_tuple_expr().is_some_and(|tuple| tuple.elts.len() == 2);
+    if elts.iter().all(is_two_tuple) {
+        KeyShape::AllTwoTuples
+    } else if elts.iter().all(|elt| !is_two_tuple(elt)) {
+        KeyShape::NotTwoTuples
+    } else {
+        KeyShape::Unknown
+    }
+}
+
+/// Classify the key shape of a `dict[K, V]` (or `Dict[K, V]`) annotation.
+fn classify_annotation_key_shape(annotation: &Expr) -> KeyShape {
+    let Expr::Subscript(ExprSubscript { slice, .. }) = annotation else {
+        return KeyShape::Unknown;
+    };
+    let Expr::Tuple(ExprTuple { elts, .. }) = slice.as_ref() else {
+        return KeyShape::Unknown;
+    };
+    let [key_type, ..] = elts.as_slice() else {
+        return KeyShape::Unknown;
+    };
+    match key_type {
+        Expr::Subscript(ExprSubscript { slice: key_slice, .. }) => {
+            match key_slice.as_ref() {
+                Expr::Tuple(ExprTuple { elts, .. }) if elts.len() == 2 => KeyShape::AllTwoTuples,
+                _ => KeyShape::Unknown,
+            }
+        }
+        // A bare, non-subscripted key type (e.g. `str`, `int`) can't be a tuple.
+        Expr::Name(_) | Expr::Attribute(_) => KeyShape::NotTwoTuples,
+        _ => KeyShape::Unknown,
+    }
+}
@@ -0,0 +1,21 @@
+//! Settings for the `pylint` plugin.
+
+use ruff_macros::CacheKey;
+
+#[derive(Debug, Clone, Copy, Default, CacheKey, PartialEq, Eq)]
+pub enum DictIterMissingItemsStrictness {
+    /// Suppress `dict-iter-missing-items` unless the dict's keys can be
+    /// proven *not* to be 2-tuples (inline literal, comprehension,
+    /// `dict.fromkeys`/`dict(zip(...))` over a literal, or annotated type).
+    Conservative,
+    /// Suppress the rule only when the dict's keys can be proven to be
+    /// 2-tuples; flag everything else, including dicts whose key type
+    /// can't be determined. This is the more aggressive, noisier default.
+    #[default]
+    AssumeScalarKeys,
+}
+
+#[derive(Debug, Clone, Default, CacheKey)]
+pub struct Settings {
+    pub dict_iter_missing_items_strictness: DictIterMissingItemsStrictness,
+}
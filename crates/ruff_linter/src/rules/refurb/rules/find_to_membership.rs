@@ -0,0 +1,213 @@
+use ruff_python_ast::{CmpOp, Expr, ExprAttribute, ExprCall, ExprCompare, Number};
+
+use ruff_diagnostics::{AlwaysFixableViolation, Diagnostic, Edit, Fix};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_python_semantic::analyze::typing::{is_dict, is_list, is_set, is_tuple};
+use ruff_python_semantic::SemanticModel;
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for membership tests on the result of `str.find()` (or
+/// `bytes.find()`) against `-1` (or `0`), e.g. `s.find(x) != -1`.
+///
+/// ## Why is this bad?
+/// `str.find` returns the index of the first occurrence of a substring, or
+/// `-1` if it's absent. Comparing that result to `-1` (or `0`) to answer a
+/// yes/no question is indirect; the `in` operator says the same thing more
+/// plainly and doesn't require the reader to remember `find`'s sentinel
+/// value.
+///
+/// ## Example
+/// ```python
+/// if s.find(substring) != -1:
+///     ...
+/// ```
+///
+/// Use instead:
+/// ```python
+/// if substring in s:
+///     ...
+/// ```
+#[violation]
+pub struct FindToMembership {
+    receiver: String,
+    member: String,
+    negated: bool,
+}
+
+impl AlwaysFixableViolation for FindToMembership {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let FindToMembership { member, receiver, negated } = self;
+        let op = if *negated { "not in" } else { "in" };
+        format!("Use `{member} {op} {receiver}` instead of comparing the result of `.find()` to a sentinel value")
+    }
+
+    fn fix_title(&self) -> String {
+        let FindToMembership { member, receiver, negated } = self;
+        let op = if *negated { "not in" } else { "in" };
+        format!("Replace with `{member} {op} {receiver}`")
+    }
+}
+
+pub(crate) fn find_to_membership(checker: &mut Checker, compare: &ExprCompare) {
+    let [op] = compare.ops.as_slice() else {
+        return;
+    };
+    let [comparator] = compare.comparators.as_slice() else {
+        return;
+    };
+
+    let Some((call, sentinel, side)) = match_find_call(&compare.left, comparator) else {
+        return;
+    };
+
+    // `match_find_call` only tells us which literal and call were compared;
+    // if the call is on the right (`0 < s.find(x)`), the comparison reads in
+    // the opposite direction from the call's perspective (`s.find(x) > 0`),
+    // so reflect the operator before classifying it.
+    let op = match side {
+        CallSide::Left => *op,
+        CallSide::Right => reflect_cmp_op(*op),
+    };
+
+    let negated = match (op, sentinel) {
+        (CmpOp::NotEq, -1) | (CmpOp::GtE, 0) => false,
+        (CmpOp::Eq, -1) | (CmpOp::Lt, 0) => true,
+        _ => return,
+    };
+
+    let Expr::Attribute(ExprAttribute { value: receiver, attr, .. }) = call.func.as_ref() else {
+        return;
+    };
+    if attr.as_str() != "find" {
+        return;
+    }
+    let [member] = call.arguments.args.as_ref() else {
+        return;
+    };
+    if !call.arguments.keywords.is_empty() {
+        return;
+    }
+
+    if !is_string_like(receiver, checker.semantic()) {
+        return;
+    }
+
+    let member_text = checker.locator().slice(member.range());
+    let receiver_text = checker.locator().slice(receiver.range());
+
+    let mut diagnostic = Diagnostic::new(
+        FindToMembership {
+            receiver: receiver_text.to_string(),
+            member: member_text.to_string(),
+            negated,
+        },
+        compare.range(),
+    );
+
+    let op = if negated { "not in" } else { "in" };
+    let replacement = format!("{member_text} {op} {receiver_text}");
+    let edit = Edit::range_replacement(replacement, compare.range());
+
+    // The replacement evaluates `member` before the receiver, the reverse of
+    // the original order, so a receiver with side effects (e.g. a function
+    // call) makes the fix unsafe; a bare name or attribute chain is safe.
+    if is_simple_expr(receiver) {
+        diagnostic.set_fix(Fix::safe_edit(edit));
+    } else {
+        diagnostic.set_fix(Fix::unsafe_edit(edit));
+    }
+
+    checker.diagnostics.push(diagnostic);
+}
+
+/// Which side of the comparison the `.find(...)` call was found on.
+#[derive(Debug, Clone, Copy)]
+enum CallSide {
+    Left,
+    Right,
+}
+
+/// If either side of the comparison is a call and the other side is the
+/// integer literal `-1` or `0`, return the call, the literal, and which side
+/// the call was on. Whether the call is to `.find(...)` is not checked here.
+fn match_find_call<'a>(left: &'a Expr, right: &'a Expr) -> Option<(&'a ExprCall, i64, CallSide)> {
+    if let Some(call) = left.as_call_expr() {
+        if let Some(sentinel) = as_int_literal(right) {
+            return Some((call, sentinel, CallSide::Left));
+        }
+    }
+    if let Some(call) = right.as_call_expr() {
+        if let Some(sentinel) = as_int_literal(left) {
+            return Some((call, sentinel, CallSide::Right));
+        }
+    }
+    None
+}
+
+/// Reflect a comparison operator for swapped operands: `a OP b` is equivalent
+/// to `b reflect_cmp_op(OP) a`.
+fn reflect_cmp_op(op: CmpOp) -> CmpOp {
+    match op {
+        CmpOp::Lt => CmpOp::Gt,
+        CmpOp::LtE => CmpOp::GtE,
+        CmpOp::Gt => CmpOp::Lt,
+        CmpOp::GtE => CmpOp::LtE,
+        other => other,
+    }
+}
+
+fn as_int_literal(expr: &Expr) -> Option<i64> {
+    let number = expr.as_number_literal_expr()?;
+    match &number.value {
+        Number::Int(int) if *int == 0 => Some(0),
+        Number::Int(int) => {
+            // `-1` parses as `UnaryOp(USub, Constant(1))`, not a single
+            // literal token, so this branch only ever sees non-negative
+            // integers; `-1` is handled by the unary-op case below.
+            let _ = int;
+            None
+        }
+        _ => None,
+    }
+    .or_else(|| {
+        let unary = expr.as_unary_op_expr()?;
+        if !matches!(unary.op, ruff_python_ast::UnaryOp::USub) {
+            return None;
+        }
+        let number = unary.operand.as_number_literal_expr()?;
+        match &number.value {
+            Number::Int(int) if *int == 1 => Some(-1),
+            _ => None,
+        }
+    })
+}
+
+/// Returns `true` unless `receiver` is known, via the semantic model, to be a
+/// type that doesn't expose `.find()` (e.g. `dict`, `list`, `set`, `tuple`).
+/// An unresolved or unknown type is assumed to be `str`/`bytes`-like.
+fn is_string_like(receiver: &Expr, semantic: &SemanticModel) -> bool {
+    let Some(name) = receiver.as_name_expr() else {
+        return true;
+    };
+    let Some(binding) = semantic.only_binding(name).map(|id| semantic.binding(id)) else {
+        return true;
+    };
+    !(is_dict(binding, semantic)
+        || is_list(binding, semantic)
+        || is_set(binding, semantic)
+        || is_tuple(binding, semantic))
+}
+
+/// Returns `true` if evaluating `expr` can't trigger a side effect: a bare
+/// name, or a chain of attribute accesses rooted in one.
+fn is_simple_expr(expr: &Expr) -> bool {
+    match expr {
+        Expr::Name(_) => true,
+        Expr::Attribute(ExprAttribute { value, .. }) => is_simple_expr(value),
+        _ => false,
+    }
+}